@@ -0,0 +1,27 @@
+fn divmod(a: i64, b: i64) -> (i64, i64) {
+    (a / b, a % b)
+}
+
+fn divisor_sum(n: i64) -> i64 {
+    let mut sum: i64 = 0;
+    let mut d: i64 = 1;
+    while d * d <= n {
+        let (q, r) = divmod(n, d);
+        if r == 0 {
+            sum += d;
+            if q != d {
+                sum += q;
+            }
+        }
+        d += 1;
+    }
+    sum
+}
+
+fn main() {
+    let mut total: i64 = 0;
+    for n in 1..20000_i64 {
+        total += divisor_sum(n);
+    }
+    std::process::exit((total & 0xFF) as i32);
+}
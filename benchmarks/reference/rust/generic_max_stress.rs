@@ -0,0 +1,39 @@
+fn max<T: PartialOrd>(a: T, b: T) -> T {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+
+fn duplicates<T: Eq>(arr: &[T]) -> usize {
+    let mut count = 0;
+    for i in 0..arr.len() {
+        for j in (i + 1)..arr.len() {
+            if arr[i] == arr[j] {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+fn main() {
+    let mut values: Vec<i64> = Vec::with_capacity(2000);
+    let mut running_max: i64 = i64::MIN;
+    for i in 0..2000_i64 {
+        let v = (i * 37) % 500;
+        values.push(v);
+        running_max = max(running_max, v);
+    }
+
+    let dup_count = duplicates(&values) as i64;
+
+    let mut float_max: f64 = f64::MIN;
+    for i in 0..100_i64 {
+        float_max = max(float_max, (i as f64) * 0.5);
+    }
+
+    let result = running_max + dup_count + float_max as i64;
+    std::process::exit((result & 0xFF) as i32);
+}
@@ -0,0 +1,45 @@
+struct ModWeights {
+    next: i64,
+    limit: i64,
+}
+
+impl ModWeights {
+    fn new(limit: i64) -> Self {
+        ModWeights { next: 0, limit }
+    }
+}
+
+impl Iterator for ModWeights {
+    type Item = (i64, i64);
+
+    fn next(&mut self) -> Option<(i64, i64)> {
+        if self.next >= self.limit {
+            return None;
+        }
+        let i = self.next;
+        self.next += 1;
+
+        let mut weight: i64 = 0;
+        if i % 2 == 0 {
+            weight += 1;
+        }
+        if i % 3 == 0 {
+            weight += 2;
+        }
+        if i % 5 == 0 {
+            weight += 3;
+        }
+        if i % 7 == 0 {
+            weight += 5;
+        }
+        Some((i, weight))
+    }
+}
+
+fn main() {
+    let mut count: i64 = 0;
+    for (_index, weight) in ModWeights::new(200000) {
+        count += weight;
+    }
+    std::process::exit((count & 0xFF) as i32);
+}
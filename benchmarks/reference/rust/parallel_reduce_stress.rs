@@ -0,0 +1,25 @@
+fn partial_sum(lo: i64, hi: i64) -> i64 {
+    let mut sum: i64 = 0;
+    for i in lo..hi {
+        sum += i * i - i / 2;
+    }
+    sum
+}
+
+fn main() {
+    let total: i64 = 500000;
+    let threads: i64 = 4;
+    let chunk = total / threads;
+
+    let sum: i64 = std::thread::scope(|scope| {
+        let mut handles = Vec::with_capacity(threads as usize);
+        for t in 0..threads {
+            let lo = t * chunk;
+            let hi = if t == threads - 1 { total } else { lo + chunk };
+            handles.push(scope.spawn(move || partial_sum(lo, hi)));
+        }
+        handles.into_iter().map(|h| h.join().unwrap()).sum()
+    });
+
+    std::process::exit((sum & 0xFF) as i32);
+}
@@ -0,0 +1,59 @@
+#[derive(Clone, Copy)]
+struct F64x4([f64; 4]);
+
+impl F64x4 {
+    fn splat(x: f64) -> Self {
+        F64x4([x; 4])
+    }
+
+    fn from_lanes(a: f64, b: f64, c: f64, d: f64) -> Self {
+        F64x4([a, b, c, d])
+    }
+
+    fn sum(self) -> f64 {
+        self.0[0] + self.0[1] + self.0[2] + self.0[3]
+    }
+}
+
+impl std::ops::Add for F64x4 {
+    type Output = F64x4;
+    fn add(self, rhs: F64x4) -> F64x4 {
+        F64x4([
+            self.0[0] + rhs.0[0],
+            self.0[1] + rhs.0[1],
+            self.0[2] + rhs.0[2],
+            self.0[3] + rhs.0[3],
+        ])
+    }
+}
+
+impl std::ops::Mul for F64x4 {
+    type Output = F64x4;
+    fn mul(self, rhs: F64x4) -> F64x4 {
+        F64x4([
+            self.0[0] * rhs.0[0],
+            self.0[1] * rhs.0[1],
+            self.0[2] * rhs.0[2],
+            self.0[3] * rhs.0[3],
+        ])
+    }
+}
+
+fn combine(x: F64x4) -> F64x4 {
+    let double = x + x;
+    let square = x * x;
+    double + square
+}
+
+fn main() {
+    let mut acc = F64x4::splat(0.0);
+    let mut i: i64 = 0;
+    while i < 500000 {
+        let lanes = F64x4::from_lanes(i as f64, (i + 1) as f64, (i + 2) as f64, (i + 3) as f64);
+        acc = acc + combine(lanes);
+        i += 4;
+    }
+
+    let sum = acc.sum();
+    std::process::exit((sum as i64 & 0xFF) as i32);
+}
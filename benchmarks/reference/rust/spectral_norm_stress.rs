@@ -0,0 +1,55 @@
+fn a(i: i64, j: i64) -> f64 {
+    let n = (i + j) * (i + j + 1) / 2 + i + 1;
+    1.0 / (n as f64)
+}
+
+fn times_a(v: &[f64], out: &mut [f64]) {
+    let n = v.len() as i64;
+    for i in 0..n {
+        let mut sum: f64 = 0.0;
+        for j in 0..n {
+            sum += a(i, j) * v[j as usize];
+        }
+        out[i as usize] = sum;
+    }
+}
+
+fn times_at(v: &[f64], out: &mut [f64]) {
+    let n = v.len() as i64;
+    for i in 0..n {
+        let mut sum: f64 = 0.0;
+        for j in 0..n {
+            sum += a(j, i) * v[j as usize];
+        }
+        out[i as usize] = sum;
+    }
+}
+
+fn times_ata(v: &[f64], out: &mut [f64]) {
+    let n = v.len();
+    let mut tmp = vec![0.0_f64; n];
+    times_a(v, &mut tmp);
+    times_at(&tmp, out);
+}
+
+fn main() {
+    let n: usize = 100;
+    let mut u = vec![1.0_f64; n];
+    let mut v = vec![0.0_f64; n];
+
+    for _ in 0..10 {
+        times_ata(&u, &mut v);
+        times_ata(&v, &mut u);
+    }
+
+    let mut vbv: f64 = 0.0;
+    let mut vv: f64 = 0.0;
+    for i in 0..n {
+        vbv += u[i] * v[i];
+        vv += v[i] * v[i];
+    }
+
+    let norm = (vbv / vv).sqrt();
+    let scaled = norm.powf(2.0) * 1000.0;
+    std::process::exit((scaled as i64 & 0xFF) as i32);
+}